@@ -6,17 +6,14 @@
 
 use bevy::prelude::*;
 use bevy_app::prelude::App;
-use bevy_malek_async::AsyncEcsPlugin;
+use bevy_malek_async::{AsyncEcsPlugin, AsyncWorld, async_access, async_startup};
 use std::time::Duration;
 
-mod utils;
-use utils::{AsyncCommands, AsyncWorld};
-
 fn main() {
     App::new()
         .add_plugins(MinimalPlugins)
+        .init_resource::<FetchCount>()
         .add_plugins(AsyncEcsPlugin)
-        .add_systems(Startup, spawn_web_request)
         .add_observer(print_response)
         .run();
 }
@@ -24,14 +21,27 @@ fn main() {
 #[derive(Event)]
 struct Response(String);
 
-// if we implemented IntoSystem for async systems this step
-// would not be nessecary.
-fn spawn_web_request(commands: AsyncCommands) {
-    commands.run(fetch_example_com);
+/// Tracks how many requests this app has fetched. Exists mainly to exercise
+/// `#[async_access]` below - a real app would just use `ResMut<FetchCount>`
+/// in an ordinary system.
+#[derive(Resource, Default)]
+struct FetchCount(u32);
+
+#[async_access(must_exist)]
+impl FetchCount {
+    fn increment(&mut self) -> u32 {
+        self.0 += 1;
+        self.0
+    }
 }
 
+// `#[async_startup]` registers this with `AsyncEcsPlugin` via `inventory` -
+// no `.add_async_systems(...)` call needed in `main` above.
+#[async_startup]
 async fn fetch_example_com(world: AsyncWorld) -> Result {
     let body = send_request("http://example.com").await?;
+    let count = world.async_fetch_count().increment().await;
+    println!("fetch #{count}");
     world.trigger(Response(body)).await;
     Ok(())
 }
@@ -0,0 +1,293 @@
+//! Proc-macros for `bevy_malek_async`: `#[async_access]` and `#[async_startup]`.
+//!
+//! `#[async_access]` turns an ordinary `impl` block for a resource type into
+//! an extension on `AsyncWorld` that performs the same access asynchronously,
+//! so callers don't have to spell out `world.run::<ResMut<MyResource>, _,
+//! _>(...)` by hand for every method. The attributed `impl` block is left
+//! untouched in the output - `#[async_access]` only reads its method
+//! signatures to decide what to generate; the method bodies keep running
+//! synchronously, just now from inside a one-shot system.
+//!
+//! `#[async_startup]` submits an async function for automatic registration
+//! on the `Startup` schedule via `inventory`, so a plugin-free "just annotate
+//! the function" background task doesn't need an explicit `add_async_systems`
+//! call anywhere.
+
+use heck::ToSnakeCase;
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{FnArg, ImplItem, ImplItemFn, ItemFn, ItemImpl, Pat, Type, parse_macro_input};
+
+/// See the crate-level docs. `#[async_access(must_exist)]` generates
+/// accessors that unwrap the resource (or component) instead of returning a
+/// `Result`, for ones guaranteed to be present (e.g. a resource registered
+/// via `init_resource` in every configuration the app runs in). Pass
+/// `#[async_access(component)]` (or `#[async_access(component, must_exist)]`)
+/// for an `impl` block over a component type instead of a resource - the
+/// generated accessors take an extra `Entity` argument and go through a
+/// `Query` rather than `Res`/`ResMut`.
+///
+/// The accessors are generated on an extension trait, not an inherent impl
+/// on `AsyncWorld` - `AsyncWorld` lives in `bevy_malek_async`, not in the
+/// crate `#[async_access]` expands in, and Rust doesn't allow inherent impls
+/// on foreign types (E0116). The trait is defined right next to the call in
+/// the expansion, so it's already in scope wherever the attributed `impl`
+/// block itself is visible; importing it (`use
+/// path::to::Async{Type}AccessorExt;`) is only needed if you call the
+/// accessor from a different module than the one the `impl` lives in.
+#[proc_macro_attribute]
+pub fn async_access(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let flags: Vec<String> = attr
+        .to_string()
+        .split(',')
+        .map(|flag| flag.trim().to_string())
+        .filter(|flag| !flag.is_empty())
+        .collect();
+    let must_exist = flags.iter().any(|flag| flag == "must_exist");
+    let is_component = flags.iter().any(|flag| flag == "component");
+    let input = parse_macro_input!(item as ItemImpl);
+
+    let resource_ty = &input.self_ty;
+    let resource_name = match &**resource_ty {
+        Type::Path(path) => path.path.segments.last().unwrap().ident.to_string(),
+        _ => {
+            return syn::Error::new_spanned(resource_ty, "#[async_access] requires a named type")
+                .to_compile_error()
+                .into();
+        }
+    };
+
+    let accessor_ident = format_ident!("Async{resource_name}Accessor");
+    let accessor_fn_ident = format_ident!("async_{}", resource_name.to_snake_case());
+    let accessor_ext_ident = format_ident!("Async{resource_name}AccessorExt");
+    // `quote!` doesn't interpolate inside `///` doc strings, so the generated
+    // docs that name the accessor are built here and emitted as `#[doc = ...]`.
+    let accessor_doc = format!(
+        "Returned by `AsyncWorld::{accessor_fn_ident}`, scoping the generated accessors below to a single [`bevy_malek_async::AsyncWorld`]."
+    );
+    let accessor_ext_doc = format!(
+        "Extension trait carrying `{accessor_fn_ident}` - see the `#[async_access]` docs for why this is a trait rather than an inherent impl."
+    );
+
+    let mut methods = Vec::new();
+    for item in &input.items {
+        if let ImplItem::Fn(method) = item {
+            match expand_method(method, resource_ty, must_exist, is_component) {
+                Ok(tokens) => methods.push(tokens),
+                Err(err) => return err.to_compile_error().into(),
+            }
+        }
+    }
+
+    quote! {
+        #input
+
+        #[doc = #accessor_doc]
+        pub struct #accessor_ident(bevy_malek_async::AsyncWorld);
+
+        #[doc = #accessor_ext_doc]
+        pub trait #accessor_ext_ident {
+            fn #accessor_fn_ident(&self) -> #accessor_ident;
+        }
+
+        impl #accessor_ext_ident for bevy_malek_async::AsyncWorld {
+            fn #accessor_fn_ident(&self) -> #accessor_ident {
+                #accessor_ident(*self)
+            }
+        }
+
+        impl #accessor_ident {
+            #(#methods)*
+        }
+    }
+    .into()
+}
+
+fn expand_method(
+    method: &ImplItemFn,
+    resource_ty: &Type,
+    must_exist: bool,
+    is_component: bool,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let sig = &method.sig;
+    let name = &sig.ident;
+    let output = match &sig.output {
+        syn::ReturnType::Default => quote! { () },
+        syn::ReturnType::Type(_, ty) => quote! { #ty },
+    };
+
+    let is_mut = match sig.inputs.first() {
+        Some(FnArg::Receiver(receiver)) => receiver.mutability.is_some(),
+        _ => {
+            return Err(syn::Error::new_spanned(
+                sig,
+                "#[async_access] methods must take &self or &mut self",
+            ));
+        }
+    };
+
+    let args: Vec<_> = sig.inputs.iter().skip(1).collect();
+    let mut arg_names = Vec::with_capacity(args.len());
+    for arg in &args {
+        let FnArg::Typed(pat_type) = arg else {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "#[async_access] only supports plain arguments after the receiver",
+            ));
+        };
+        let Pat::Ident(pat_ident) = &*pat_type.pat else {
+            return Err(syn::Error::new_spanned(
+                &pat_type.pat,
+                "#[async_access] arguments must be simple identifiers",
+            ));
+        };
+        arg_names.push(pat_ident.ident.clone());
+    }
+
+    if is_component {
+        return Ok(expand_component_method(
+            name,
+            &output,
+            resource_ty,
+            is_mut,
+            must_exist,
+            &args,
+            &arg_names,
+        ));
+    }
+
+    let (param_ty, call) = if is_mut {
+        (
+            quote! { bevy_ecs::system::ResMut<#resource_ty> },
+            quote! { move |mut resource: bevy_ecs::system::ResMut<#resource_ty>| resource.#name(#(#arg_names),*) },
+        )
+    } else {
+        (
+            quote! { bevy_ecs::system::Res<#resource_ty> },
+            quote! { move |resource: bevy_ecs::system::Res<#resource_ty>| resource.#name(#(#arg_names),*) },
+        )
+    };
+
+    Ok(if must_exist {
+        quote! {
+            pub async fn #name(&self, #(#args),*) -> #output {
+                self.0
+                    .run::<#param_ty, _, _>(bevy_app::Update, #call)
+                    .await
+            }
+        }
+    } else {
+        quote! {
+            pub async fn #name(&self, #(#args),*) -> bevy_ecs::error::Result<#output> {
+                self.0
+                    .run::<::core::option::Option<#param_ty>, _, _>(bevy_app::Update, move |resource| {
+                        resource.map(#call).ok_or_else(|| {
+                            bevy_ecs::error::BevyError::from(::std::io::Error::other(format!(
+                                "{} is not present as a resource",
+                                ::core::any::type_name::<#resource_ty>()
+                            )))
+                        })
+                    })
+                    .await
+            }
+        }
+    })
+}
+
+/// Component-flavored counterpart of the resource path in `expand_method`:
+/// goes through a `Query<&#resource_ty>`/`Query<&mut #resource_ty>` indexed
+/// by an `Entity` the caller now has to pass in, instead of `Res`/`ResMut`.
+fn expand_component_method(
+    name: &syn::Ident,
+    output: &proc_macro2::TokenStream,
+    resource_ty: &Type,
+    is_mut: bool,
+    must_exist: bool,
+    args: &[&FnArg],
+    arg_names: &[syn::Ident],
+) -> proc_macro2::TokenStream {
+    let query_ty = if is_mut {
+        quote! { bevy_ecs::system::Query<&mut #resource_ty> }
+    } else {
+        quote! { bevy_ecs::system::Query<&#resource_ty> }
+    };
+    let mut_kw = if is_mut {
+        quote! { mut }
+    } else {
+        quote! {}
+    };
+    let get_call = if is_mut {
+        quote! { query.get_mut(entity) }
+    } else {
+        quote! { query.get(entity) }
+    };
+
+    if must_exist {
+        let bind = if is_mut {
+            quote! { let mut component = #get_call.unwrap(); }
+        } else {
+            quote! { let component = #get_call.unwrap(); }
+        };
+        quote! {
+            pub async fn #name(&self, entity: bevy_ecs::entity::Entity, #(#args),*) -> #output {
+                self.0
+                    .run::<#query_ty, _, _>(bevy_app::Update, move |#mut_kw query: #query_ty| {
+                        #bind
+                        component.#name(#(#arg_names),*)
+                    })
+                    .await
+            }
+        }
+    } else {
+        let invoke = if is_mut {
+            quote! { |mut component| component.#name(#(#arg_names),*) }
+        } else {
+            quote! { |component| component.#name(#(#arg_names),*) }
+        };
+        quote! {
+            pub async fn #name(&self, entity: bevy_ecs::entity::Entity, #(#args),*) -> bevy_ecs::error::Result<#output> {
+                self.0
+                    .run::<#query_ty, _, _>(bevy_app::Update, move |#mut_kw query: #query_ty| {
+                        #get_call.ok().map(#invoke).ok_or_else(|| {
+                            bevy_ecs::error::BevyError::from(::std::io::Error::other(format!(
+                                "entity {entity} has no {} component",
+                                ::core::any::type_name::<#resource_ty>()
+                            )))
+                        })
+                    })
+                    .await
+            }
+        }
+    }
+}
+
+/// See the crate-level docs. The annotated function must have the same shape
+/// `AsyncCommands::run`/`add_async_systems` already accept: `async fn(world:
+/// AsyncWorld) -> bevy_ecs::error::Result`. The generated registration calls
+/// `AddAsyncSystems::add_async_systems` through its fully-qualified path, so
+/// unlike a plain `.add_async_systems(...)` method call this doesn't require
+/// the caller to have `AddAsyncSystems` in scope - `#[async_startup]` really
+/// is just-annotate-it, with no import or plugin setup beyond `AsyncEcsPlugin`
+/// itself.
+#[proc_macro_attribute]
+pub fn async_startup(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as ItemFn);
+    let name = &input.sig.ident;
+
+    quote! {
+        #input
+
+        ::inventory::submit! {
+            bevy_malek_async::AsyncStartupSystem {
+                register: |app: &mut bevy_app::App| {
+                    <bevy_app::App as bevy_malek_async::AddAsyncSystems>::add_async_systems(
+                        app,
+                        bevy_app::Startup,
+                        #name,
+                    );
+                },
+            }
+        }
+    }
+    .into()
+}
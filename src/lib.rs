@@ -1,19 +1,33 @@
+use async_executor::{Executor, LocalExecutor};
 use bevy_app::{App, Plugin, PostStartup, PostUpdate, PreStartup, PreUpdate, Startup, Update};
+use bevy_asset::{Asset, AssetServer, Handle, LoadState};
 use bevy_ecs::{
+    error::{BevyError, ErrorContext, Result},
+    event::Event,
+    observer::On,
     prelude::{FromWorld, Resource},
-    system::{SystemParam, SystemState},
+    schedule::ScheduleLabel,
+    system::{Commands, NonSend, Res, ResMut, SystemParam, SystemState},
     world::{World, WorldId, unsafe_world_cell::UnsafeWorldCell},
 };
+#[doc(inline)]
+pub use bevy_malek_async_macros::{async_access, async_startup};
 use bevy_platform::collections::HashMap;
+use bevy_state::state::{State, States};
+use bevy_utils::DebugName;
 use crossbeam::sync::WaitGroup;
 use std::{
     marker::PhantomData,
     pin::Pin,
+    rc::Rc,
     sync::{Arc, Mutex, OnceLock},
     task::{Context, Poll, Waker},
 };
 
-static ASYNC_ECS_WORLD_ACCESS: OnceLock<Mutex<Option<UnsafeWorldCell>>> = OnceLock::new();
+// Keyed by `WorldId` rather than a single slot so that interleaved worlds (the
+// main world and sub-apps such as the render world, each driven by their own
+// schedule) don't clobber one another's access while both are in flight.
+static ASYNC_ECS_WORLD_ACCESS: OnceLock<Mutex<HashMap<WorldId, UnsafeWorldCell>>> = OnceLock::new();
 static ASYNC_ECS_WAKER_LIST: OnceLock<Mutex<HashMap<WorldId, Vec<Waker>>>> = OnceLock::new();
 
 pub async fn async_access<P, Func, Out>(world_id: WorldId, ecs_access: Func) -> Out
@@ -42,13 +56,30 @@ where
     type Output = Out;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let wanted_world = self.3;
         if let Some(async_ecs_world_access) = ASYNC_ECS_WORLD_ACCESS.get()
-            && let Some(wc) = async_ecs_world_access.lock().unwrap().as_mut()
+            && let Some(wc) = async_ecs_world_access
+                .lock()
+                .unwrap()
+                .get_mut(&wanted_world)
+            // `run_async_ecs_accesses` hands out exactly one ticket per waker it
+            // woke this tick, so a task that re-enters `poll` within the same
+            // tick (e.g. `wait_until`'s loop re-running its predicate because it
+            // hasn't yet observed `Some`) can't keep completing synchronously
+            // forever: once tickets run out this falls through to parking below,
+            // same as if the world weren't installed at all.
+            && unsafe {
+                wc.get_resource_mut::<AsyncEcsCounter>()
+                    .unwrap()
+                    .0
+                    .lock()
+                    .unwrap()
+                    .pop()
+                    .is_some()
+            }
         {
             let out;
-            let world_id;
             unsafe {
-                world_id = wc.world().id();
                 // SAFETY: This is safe because we have a mutex around our world cell, so only one thing can have access to it at a time.
                 let mut system_state: SystemState<P> = SystemState::new(wc.world_mut());
                 {
@@ -58,23 +89,23 @@ where
                     out = self.as_mut().2.take().unwrap()(state);
                 }
                 system_state.apply(wc.world_mut());
-                wc.get_resource_mut::<AsyncEcsCounter>()
-                    .unwrap()
-                    .0
-                    .lock()
-                    .unwrap()
-                    .pop();
             }
             Poll::Ready(out)
         } else {
+            // Either nobody is servicing a world right now, or it's a different
+            // world's turn (e.g. a sub-app's schedule is running) - either way we
+            // park until our own `WorldId` is serviced.
             let mut hashmap = ASYNC_ECS_WAKER_LIST
                 .get_or_init(|| Mutex::new(HashMap::new()))
                 .lock()
                 .unwrap();
-            if !hashmap.contains_key(&self.3) {
-                hashmap.insert(self.3.clone(), Vec::new());
+            if !hashmap.contains_key(&wanted_world) {
+                hashmap.insert(wanted_world, Vec::new());
             }
-            hashmap.get_mut(&self.3).unwrap().push(cx.waker().clone());
+            hashmap
+                .get_mut(&wanted_world)
+                .unwrap()
+                .push(cx.waker().clone());
             Poll::Pending
         }
     }
@@ -84,12 +115,14 @@ fn run_async_ecs_accesses(world: &mut World) {
     let world_id = world.id();
     unsafe {
         ASYNC_ECS_WORLD_ACCESS
-            .get_or_init(|| Mutex::new(None))
+            .get_or_init(|| Mutex::new(HashMap::new()))
             .lock()
             .unwrap()
             // SAFETY: This mem transmute is safe only because we drop it after, and our ASYNC_ECS_WORLD_ACCESS is private, and we don't clone it
-            // where we do use it, so the lifetime doesn't get propagated anywhere.
-            .replace(std::mem::transmute(world.as_unsafe_world_cell()));
+            // where we do use it, so the lifetime doesn't get propagated anywhere. We only ever
+            // insert/remove our own `world_id` entry, so a sub-app's schedule running while this
+            // one is serviced can't observe or overwrite it.
+            .insert(world_id, std::mem::transmute(world.as_unsafe_world_cell()));
     }
     let mut num_wakers = 0;
     if let Some(wakers) = ASYNC_ECS_WAKER_LIST
@@ -116,6 +149,36 @@ fn run_async_ecs_accesses(world: &mut World) {
             waker.wake();
         }
         if num_wakers > 0 {
+            // Waking a task only marks it runnable on its executor - unlike the old
+            // thread-per-task model there's no OS thread concurrently polling it for us,
+            // so we drive both executors ourselves until nothing is left to run. A woken
+            // task may be sitting on either one (`run_local` tasks park the exact same
+            // waker this handshake wakes, just onto `AsyncLocalExecutor` instead of
+            // `AsyncExecutor`), so ticking only the former would leave local tasks'
+            // tickets forever undropped and `wg.wait()` below would never return.
+            //
+            // The world cell stays installed for the whole drain, but `SystemParamThing::
+            // poll` only grants access while a ticket remains, and we only ever hand out
+            // `num_wakers` of them above - so a task that re-enters world access within
+            // this same drain (e.g. a `wait_until` loop re-checking its predicate) runs
+            // out of tickets after its one grant and parks a fresh waker for next tick
+            // instead of spinning here. By the time neither executor can make progress,
+            // every woken task has either finished its access (dropping its ticket) or
+            // parked on something else, so `wg.wait()` below just confirms the handshake
+            // closed.
+            let executor = world.get_resource::<AsyncExecutor>().unwrap().0.clone();
+            let local_executor = world
+                .get_non_send_resource::<AsyncLocalExecutor>()
+                .unwrap()
+                .0
+                .clone();
+            loop {
+                let mut progressed = executor.try_tick();
+                progressed |= local_executor.try_tick();
+                if !progressed {
+                    break;
+                }
+            }
             wg.wait();
         }
     }
@@ -124,25 +187,103 @@ fn run_async_ecs_accesses(world: &mut World) {
         .unwrap()
         .lock()
         .unwrap()
-        .take()
+        .remove(&world_id)
         .unwrap();
 }
 
-pub struct AsyncPlugin;
+pub struct AsyncEcsPlugin;
 
-impl Plugin for AsyncPlugin {
+impl Plugin for AsyncEcsPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<WorldIdRes>()
             .init_resource::<AsyncEcsCounter>()
-            .add_systems(PreStartup, run_async_ecs_accesses)
-            .add_systems(Startup, run_async_ecs_accesses)
-            .add_systems(PostStartup, run_async_ecs_accesses)
-            .add_systems(PreUpdate, run_async_ecs_accesses)
-            .add_systems(Update, run_async_ecs_accesses)
-            .add_systems(PostUpdate, run_async_ecs_accesses);
+            .init_resource::<AsyncExecutor>()
+            .init_non_send_resource::<AsyncLocalExecutor>()
+            .add_systems(
+                PreStartup,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Startup,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostStartup,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PreUpdate,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                Update,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                PostUpdate,
+                (
+                    run_async_ecs_accesses,
+                    tick_async_executor,
+                    tick_local_executor,
+                )
+                    .chain(),
+            );
+
+        for registration in inventory::iter::<AsyncStartupSystem> {
+            (registration.register)(app);
+        }
     }
 }
 
+/// A `#[async_startup]`-annotated function, submitted via `inventory::submit!`
+/// so [`AsyncEcsPlugin::build`] can schedule it without the user calling
+/// `add_async_systems` themselves. Always targets the `Startup` schedule -
+/// the macro that produces these is named after it.
+pub struct AsyncStartupSystem {
+    pub register: fn(&mut App),
+}
+inventory::collect!(AsyncStartupSystem);
+
+/// Drains every task still runnable on [`AsyncExecutor`] after world access has
+/// closed for this schedule point - e.g. tasks that haven't reached their first
+/// `.await` yet, or ones parked on something other than the world (a timer, an
+/// HTTP socket, `blocking::unblock`, ...) that happened to become ready this frame.
+fn tick_async_executor(executor: Res<AsyncExecutor>) {
+    while executor.0.try_tick() {}
+}
+
+/// Drains every task still runnable on [`AsyncLocalExecutor`]. Requiring
+/// `NonSend` pins this system to the thread that owns the `World`, which is
+/// exactly the thread `!Send` local tasks are allowed to touch it from.
+fn tick_local_executor(local_executor: NonSend<AsyncLocalExecutor>) {
+    while local_executor.0.try_tick() {}
+}
+
 #[derive(Resource)]
 pub struct WorldIdRes(pub WorldId);
 impl FromWorld for WorldIdRes {
@@ -158,3 +299,418 @@ impl Default for AsyncEcsCounter {
         Self(Arc::new(Mutex::new(Vec::new())))
     }
 }
+
+/// The single, work-stealing-free cooperative executor every `Send` async task
+/// is spawned onto, in place of a dedicated OS thread per task. Ticked from
+/// [`tick_async_executor`] and from [`run_async_ecs_accesses`] whenever it
+/// wakes a task waiting on world access, so bounded thread usage doesn't come
+/// at the cost of a frame of latency on every world round-trip.
+#[derive(Resource, Clone)]
+pub struct AsyncExecutor(pub Arc<Executor<'static>>);
+impl Default for AsyncExecutor {
+    fn default() -> Self {
+        Self(Arc::new(Executor::new()))
+    }
+}
+
+/// Companion to [`AsyncExecutor`] for tasks that are `?Send` - non-Send HTTP
+/// clients, `Rc`-based state, GPU handles and the like. Registered as a
+/// *non-send* resource, so it only ever exists on the thread that owns the
+/// `World`, and every task pushed onto it is guaranteed to stay there too.
+///
+/// Don't construct this directly; spawn onto it through
+/// `AsyncCommands::run_local`.
+#[derive(Clone)]
+pub struct AsyncLocalExecutor(pub Rc<LocalExecutor<'static>>);
+impl Default for AsyncLocalExecutor {
+    fn default() -> Self {
+        Self(Rc::new(LocalExecutor::new()))
+    }
+}
+
+/// Like [`async_access`], but for closures that need direct `&mut World`
+/// access (e.g. dispatching the default error handler) instead of a typed
+/// [`SystemParam`].
+pub async fn async_exclusive_access<Func, Out>(world_id: WorldId, exclusive_access: Func) -> Out
+where
+    Func: FnOnce(&mut World) -> Out,
+{
+    ExclusiveWorldThing(Some(exclusive_access), world_id).await
+}
+
+struct ExclusiveWorldThing<Func>(Option<Func>, WorldId);
+
+impl<Func> Unpin for ExclusiveWorldThing<Func> {}
+
+impl<Func, Out> Future for ExclusiveWorldThing<Func>
+where
+    Func: FnOnce(&mut World) -> Out,
+{
+    type Output = Out;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let wanted_world = self.1;
+        if let Some(async_ecs_world_access) = ASYNC_ECS_WORLD_ACCESS.get()
+            && let Some(wc) = async_ecs_world_access
+                .lock()
+                .unwrap()
+                .get_mut(&wanted_world)
+            // See the matching comment on `SystemParamThing::poll` - this caps us to
+            // one grant per tick too, for the same reason.
+            && unsafe {
+                wc.get_resource_mut::<AsyncEcsCounter>()
+                    .unwrap()
+                    .0
+                    .lock()
+                    .unwrap()
+                    .pop()
+                    .is_some()
+            }
+        {
+            // SAFETY: This is safe because we have a mutex around our world cell, so
+            // only one thing can have access to it at a time.
+            let out = unsafe { self.as_mut().0.take().unwrap()(wc.world_mut()) };
+            Poll::Ready(out)
+        } else {
+            let mut hashmap = ASYNC_ECS_WAKER_LIST
+                .get_or_init(|| Mutex::new(HashMap::new()))
+                .lock()
+                .unwrap();
+            if !hashmap.contains_key(&wanted_world) {
+                hashmap.insert(wanted_world, Vec::new());
+            }
+            hashmap
+                .get_mut(&wanted_world)
+                .unwrap()
+                .push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+pub trait AsyncSystem<M>: 'static + Send {
+    fn run(self, world: AsyncWorld) -> impl Future<Output = Result>;
+}
+impl<T, F> AsyncSystem<F> for T
+where
+    T: 'static + Send + FnOnce(AsyncWorld) -> F,
+    F: Future<Output = Result>,
+{
+    fn run(self, world: AsyncWorld) -> impl Future<Output = Result> {
+        (self)(world)
+    }
+}
+
+/// Like [`AsyncSystem`] but for tasks run via `AsyncCommands::run_local` -
+/// the future only has to live on the thread that owns the `World`, so it
+/// may hold `!Send` state (a non-Send HTTP client, `Rc`-based data, ...).
+pub trait LocalAsyncSystem<M>: 'static {
+    fn run(self, world: AsyncWorld) -> impl Future<Output = Result>;
+}
+impl<T, F> LocalAsyncSystem<F> for T
+where
+    T: 'static + FnOnce(AsyncWorld) -> F,
+    F: Future<Output = Result>,
+{
+    fn run(self, world: AsyncWorld) -> impl Future<Output = Result> {
+        (self)(world)
+    }
+}
+
+/// Ergonomic wrapper around [`WorldId`] for async ECS access.
+///
+/// Clone this freely - it's just a [`WorldId`] under the hood.
+#[derive(Clone, Copy, SystemParam)]
+pub struct AsyncWorld {
+    world_id: WorldId,
+}
+
+impl AsyncWorld {
+    pub fn new(world_id: WorldId) -> Self {
+        Self { world_id }
+    }
+
+    /// Schedule a one-shot system to run on the given schedule label,
+    /// returning the system's output once it has been executed.
+    ///
+    /// `schedule` only documents which point in the frame `system` is meant
+    /// to run at - `run_async_ecs_accesses` is chained into every schedule
+    /// `AsyncEcsPlugin` registers, so a pending access is serviced at the
+    /// very next one of them to run, whichever that is.
+    pub async fn run<P, Func, Out>(&self, schedule: impl ScheduleLabel, system: Func) -> Out
+    where
+        P: SystemParam + 'static,
+        for<'w, 's> Func: FnOnce(P::Item<'w, 's>) -> Out,
+    {
+        let _ = schedule;
+        async_access::<P, _, _>(self.world_id, system).await
+    }
+
+    /// Schedule an exclusive system (one taking `&mut World`) to run on the
+    /// given schedule label, returning its output once executed. See the
+    /// note on [`run`](Self::run) about `schedule`.
+    pub async fn run_exclusive<Func, Out>(&self, schedule: impl ScheduleLabel, system: Func) -> Out
+    where
+        Func: FnOnce(&mut World) -> Out,
+    {
+        let _ = schedule;
+        async_exclusive_access(self.world_id, system).await
+    }
+
+    #[allow(unused)]
+    pub async fn with_resource<R: Resource, Out>(
+        &self,
+        func: impl FnOnce(ResMut<R>) -> Out,
+    ) -> Out {
+        self.run::<ResMut<R>, _, _>(Update, func).await
+    }
+
+    pub async fn trigger<E: Event>(&self, event: E)
+    where
+        for<'a> E::Trigger<'a>: Default,
+    {
+        self.run::<Commands, _, _>(Update, move |mut commands| {
+            commands.trigger(event);
+        })
+        .await;
+    }
+
+    pub async fn handle_error(&self, err: impl Into<BevyError>, cx: ErrorContext) {
+        self.run_exclusive(Update, move |world: &mut World| {
+            world.default_error_handler()(err.into(), cx);
+        })
+        .await;
+    }
+
+    /// Re-runs a read-only closure once per schedule tick until it returns
+    /// `Some(T)`, resolving with that value. The building block every other
+    /// `wait_*` combinator on this type is written in terms of.
+    ///
+    /// `run`'s world access is capped to `num_wakers` grants per tick, one
+    /// per task woken that tick, so a lone `wait_until` loop can't spin in
+    /// place forever - once its own ticket is spent the `.await` below
+    /// genuinely suspends. That cap is a shared pool rather than one ticket
+    /// reserved per task, though: if several tasks are woken the same tick,
+    /// a `wait_until` loop that keeps coming back `None` can keep grabbing
+    /// tickets meant for the others, re-running its predicate more than
+    /// once while they get re-parked without making progress. They aren't
+    /// starved forever - `run_async_ecs_accesses` runs again at the next
+    /// schedule point and hands out a fresh batch of tickets - so don't
+    /// assume this loop costs exactly one schedule run per iteration when
+    /// other `wait_*` tasks are in flight on the same world.
+    pub async fn wait_until<P, Func, T>(&self, mut predicate: Func) -> T
+    where
+        P: SystemParam + 'static,
+        T: 'static,
+        for<'w, 's> Func: FnMut(P::Item<'w, 's>) -> Option<T>,
+    {
+        loop {
+            if let Some(value) = self.run::<P, _, _>(Update, &mut predicate).await {
+                return value;
+            }
+        }
+    }
+
+    /// Resolves once `State<S>` equals `target`.
+    pub async fn wait_for_state<S: States>(&self, target: S) {
+        self.wait_until::<Res<State<S>>, _, _>(move |state: Res<State<S>>| {
+            (*state.get() == target).then_some(())
+        })
+        .await
+    }
+
+    /// Registers a one-shot observer for `E` and resolves with the first
+    /// matching event it sees. Unlike a fresh `EventReader`, an observer
+    /// stays registered across ticks, so it can't miss an event fired
+    /// between two polls - it's despawned once this call has received the
+    /// first matching event, so this really is one-shot rather than leaking an observer
+    /// that keeps firing (and sending into an already-dropped channel) for
+    /// the rest of the app's life.
+    pub async fn wait_for_event<E: Event + Clone>(&self) -> E {
+        let (tx, rx) = crossbeam::channel::bounded(1);
+        let observer = self
+            .run::<Commands, _, _>(Update, move |mut commands| {
+                commands
+                    .add_observer(move |event: On<E>| {
+                        let _ = tx.try_send((*event).clone());
+                    })
+                    .id()
+            })
+            .await;
+        // The observer above may fire on any tick after this point, including
+        // ones before this `wait_until` has even been polled once - `rx` is
+        // bounded(1) and buffers the first send, so nothing is missed in the gap;
+        // `try_send` drops any later match instead of blocking the world thread.
+        let value = self
+            .wait_until::<(), _, _>(move |_: ()| rx.try_recv().ok())
+            .await;
+        self.run_exclusive(Update, move |world: &mut World| {
+            world.despawn(observer);
+        })
+        .await;
+        value
+    }
+
+    /// Kick off loading an asset through the `AssetServer`, returning a
+    /// handle you can later `wait_loaded().await` on - no hand-rolled polling
+    /// against `Assets<A>` required.
+    pub async fn load_asset<A: Asset>(&self, path: impl Into<String>) -> AsyncAsset<A> {
+        let path = path.into();
+        let handle = self
+            .run::<Res<AssetServer>, _, _>(Update, move |asset_server: Res<AssetServer>| {
+                asset_server.load::<A>(path)
+            })
+            .await;
+        AsyncAsset {
+            handle,
+            world: *self,
+        }
+    }
+}
+
+impl From<WorldId> for AsyncWorld {
+    fn from(world_id: WorldId) -> Self {
+        Self::new(world_id)
+    }
+}
+
+/// A [`Handle`] paired with the [`AsyncWorld`] that fetched it, so its load
+/// progress can be awaited.
+pub struct AsyncAsset<A: Asset> {
+    handle: Handle<A>,
+    world: AsyncWorld,
+}
+
+impl<A: Asset> AsyncAsset<A> {
+    pub fn handle(&self) -> &Handle<A> {
+        &self.handle
+    }
+
+    /// Polls `AssetServer::get_load_state` once per schedule tick until the
+    /// asset finishes loading, resolving with the handle once it does (or
+    /// erroring if the asset fails to load).
+    pub async fn wait_loaded(&self) -> Result<Handle<A>> {
+        loop {
+            let handle = self.handle.clone();
+            let state = self
+                .world
+                .run::<Res<AssetServer>, _, _>(Update, move |asset_server: Res<AssetServer>| {
+                    asset_server.get_load_state(&handle)
+                })
+                .await;
+            match state {
+                Some(LoadState::Loaded) => return Ok(self.handle.clone()),
+                // `err` is an `Arc<AssetLoadError>`, so it can't be moved out of -
+                // go through `to_string()` instead of trying to convert the error
+                // itself.
+                Some(LoadState::Failed(err)) => {
+                    return Err(std::io::Error::other(err.to_string()).into());
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+/// `SystemParam` for firing off async work from an ordinary system, without
+/// needing a `WorldId` in hand - its [`AsyncWorld`] field takes the id of the
+/// world the system runs in.
+#[derive(SystemParam)]
+pub struct AsyncCommands<'w> {
+    world: AsyncWorld,
+    executor: Res<'w, AsyncExecutor>,
+    local_executor: NonSend<'w, AsyncLocalExecutor>,
+}
+impl<'w> AsyncCommands<'w> {
+    pub fn run<S, M>(&self, system: S)
+    where
+        S: AsyncSystem<M>,
+    {
+        let world = self.world;
+        self.executor
+            .0
+            .spawn(async move {
+                if let Err(err) = system.run(world).await {
+                    world
+                        .handle_error(
+                            err,
+                            ErrorContext::Command {
+                                name: DebugName::type_name::<S>(),
+                            },
+                        )
+                        .await;
+                }
+            })
+            .detach();
+    }
+
+    /// Like [`run`](Self::run), but for systems whose future is `?Send`. The
+    /// task is pushed onto the thread-local executor instead, so it may only
+    /// touch the `World` from the thread that owns it - the same thread the
+    /// `UnsafeWorldCell` handshake in `run_async_ecs_accesses` already
+    /// restricts world access to.
+    pub fn run_local<S, M>(&self, system: S)
+    where
+        S: LocalAsyncSystem<M>,
+    {
+        let world = self.world;
+        self.local_executor
+            .0
+            .spawn(async move {
+                if let Err(err) = system.run(world).await {
+                    world
+                        .handle_error(
+                            err,
+                            ErrorContext::Command {
+                                name: DebugName::type_name::<S>(),
+                            },
+                        )
+                        .await;
+                }
+            })
+            .detach();
+    }
+}
+
+/// Lets an [`AsyncSystem`] be registered directly wherever a regular system is
+/// expected - e.g. `app.add_async_systems(Startup, fetch_example_com)` -
+/// instead of going through an imperative [`AsyncCommands::run`] call from
+/// inside a plain Bevy system.
+pub trait AddAsyncSystems {
+    fn add_async_systems<S, M>(&mut self, schedule: impl ScheduleLabel, system: S) -> &mut Self
+    where
+        S: AsyncSystem<M>;
+}
+
+impl AddAsyncSystems for App {
+    fn add_async_systems<S, M>(&mut self, schedule: impl ScheduleLabel, system: S) -> &mut Self
+    where
+        S: AsyncSystem<M>,
+    {
+        let mut system = Some(system);
+        self.add_systems(
+            schedule,
+            move |executor: Res<AsyncExecutor>, world_id: Res<WorldIdRes>| {
+                let Some(system) = system.take() else {
+                    return;
+                };
+                let world = AsyncWorld::new(world_id.0);
+                executor
+                    .0
+                    .spawn(async move {
+                        if let Err(err) = system.run(world).await {
+                            world
+                                .handle_error(
+                                    err,
+                                    ErrorContext::Command {
+                                        name: DebugName::type_name::<S>(),
+                                    },
+                                )
+                                .await;
+                        }
+                    })
+                    .detach();
+            },
+        )
+    }
+}